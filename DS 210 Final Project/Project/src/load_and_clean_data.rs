@@ -3,6 +3,60 @@ use std::collections::HashSet;
 use std::error::Error;
 use crate::Country;
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalingParams {
+    pub communicable_mean: f64,
+    pub communicable_std: f64,
+    pub non_communicable_mean: f64,
+    pub non_communicable_std: f64,
+    pub co2_mean: f64,
+    pub co2_std: f64,
+}
+
+fn mean_and_std(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+// Rewrites each feature to its z-score `(x - mean) / std` so that communicable,
+// non_communicable, and co2 are on comparable scales before any distance is
+// computed; otherwise whichever column has the largest raw magnitude would
+// dominate every `euclidean_distance`. Zero-variance features are left as-is
+// since dividing by a zero std would be meaningless. Returns the means/stds
+// used so results can be mapped back to original units later.
+pub fn standardize(countries: &mut [Country]) -> ScalingParams {
+    let communicable: Vec<f64> = countries.iter().map(|country| country.communicable).collect();
+    let non_communicable: Vec<f64> = countries.iter().map(|country| country.non_communicable).collect();
+    let co2: Vec<f64> = countries.iter().map(|country| country.co2).collect();
+
+    let (communicable_mean, communicable_std) = mean_and_std(&communicable);
+    let (non_communicable_mean, non_communicable_std) = mean_and_std(&non_communicable);
+    let (co2_mean, co2_std) = mean_and_std(&co2);
+
+    for country in countries.iter_mut() {
+        if communicable_std > 0.0 {
+            country.communicable = (country.communicable - communicable_mean) / communicable_std;
+        }
+        if non_communicable_std > 0.0 {
+            country.non_communicable = (country.non_communicable - non_communicable_mean) / non_communicable_std;
+        }
+        if co2_std > 0.0 {
+            country.co2 = (country.co2 - co2_mean) / co2_std;
+        }
+    }
+
+    ScalingParams {
+        communicable_mean,
+        communicable_std,
+        non_communicable_mean,
+        non_communicable_std,
+        co2_mean,
+        co2_std,
+    }
+}
+
 pub fn load_and_clean_data(file_path: &str) -> Result<Vec<Country>, Box<dyn Error>> {
     let mut reader = ReaderBuilder::new().has_headers(true).from_path(file_path)?;
     let mut seen_countries = HashSet::new();
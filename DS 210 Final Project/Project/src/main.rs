@@ -1,10 +1,18 @@
 mod load_and_clean_data;
 
-use load_and_clean_data::load_and_clean_data;
+use load_and_clean_data::{load_and_clean_data, standardize};
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::seq::SliceRandom;
-use std::collections::HashMap;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::error::Error;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InitMethod {
+    Random,
+    KmeansPlusPlus,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 struct Country {
     name: String,
@@ -31,9 +39,53 @@ fn convert_to_hashable(country: &Country) -> HashableCountry {
     }
 }
 
-fn initialize_centroids(countries: &[Country], k: usize) -> Vec<Country> {
+fn initialize_centroids(countries: &[Country], k: usize, init: InitMethod) -> Vec<Country> {
+    match init {
+        InitMethod::Random => {
+            let mut rng = rand::thread_rng();
+            countries.choose_multiple(&mut rng, k).cloned().collect()
+        }
+        InitMethod::KmeansPlusPlus => kmeans_plus_plus_centroids(countries, k),
+    }
+}
+
+// k-means++ seeding: pick the first centroid uniformly, then repeatedly sample
+// the next one with probability proportional to its squared distance to the
+// nearest centroid already chosen, so far-apart regions get spread out early.
+fn kmeans_plus_plus_centroids(countries: &[Country], k: usize) -> Vec<Country> {
     let mut rng = rand::thread_rng();
-    countries.choose_multiple(&mut rng, k).cloned().collect()
+    let mut centroids: Vec<Country> = Vec::new();
+
+    if countries.is_empty() || k == 0 {
+        return centroids;
+    }
+
+    centroids.push(countries.choose(&mut rng).unwrap().clone());
+
+    while centroids.len() < k && centroids.len() < countries.len() {
+        let sq_distances: Vec<f64> = countries
+            .iter()
+            .map(|country| {
+                centroids
+                    .iter()
+                    .map(|centroid| euclidean_distance(country, centroid).powi(2))
+                    .fold(f64::MAX, f64::min)
+            })
+            .collect();
+
+        let next = if sq_distances.iter().all(|&d| d == 0.0) {
+            // All remaining points are duplicates of chosen centroids; fall back
+            // to a uniform pick since weighted sampling would be degenerate.
+            countries.choose(&mut rng).unwrap().clone()
+        } else {
+            let weights = WeightedIndex::new(&sq_distances).unwrap();
+            countries[weights.sample(&mut rng)].clone()
+        };
+
+        centroids.push(next);
+    }
+
+    centroids
 }
 
 fn assign_clusters(countries: &mut [Country], centroids: &[Country]) {
@@ -93,8 +145,13 @@ fn euclidean_distance(country: &Country, centroid: &Country) -> f64 {
     .sqrt()
 }
 
-fn kmeans(mut countries: Vec<Country>, k: usize, max_iterations: usize) -> Vec<Country> {
-    let mut centroids = initialize_centroids(&countries, k);
+fn kmeans(
+    mut countries: Vec<Country>,
+    k: usize,
+    max_iterations: usize,
+    init: InitMethod,
+) -> Vec<Country> {
+    let mut centroids = initialize_centroids(&countries, k, init);
 
     for _ in 0..max_iterations {
         assign_clusters(&mut countries, &centroids);
@@ -110,7 +167,207 @@ fn kmeans(mut countries: Vec<Country>, k: usize, max_iterations: usize) -> Vec<C
     countries
 }
 
-fn build_graph(countries: &[Country], threshold: f64) -> Vec<(String, Vec<String>)> {
+fn wcss(countries: &[Country], centroids: &[Country]) -> f64 {
+    countries
+        .iter()
+        .filter_map(|country| {
+            country
+                .cluster
+                .map(|cluster| euclidean_distance(country, &centroids[cluster]).powi(2))
+        })
+        .sum()
+}
+
+// Mean silhouette coefficient over all points: for each point, a is its mean
+// distance to its own cluster-mates and b is the smallest mean distance to
+// any other cluster. A point alone in its cluster (no cluster-mates) or with
+// no other clusters to compare against scores 0 rather than undefined.
+fn mean_silhouette(countries: &[Country], k: usize) -> f64 {
+    if countries.is_empty() {
+        return 0.0;
+    }
+
+    let mean_distance_to = |country: &Country, cluster: usize| -> Option<f64> {
+        let members: Vec<&Country> = countries
+            .iter()
+            .filter(|c| c.cluster == Some(cluster) && c.name != country.name)
+            .collect();
+        if members.is_empty() {
+            None
+        } else {
+            Some(
+                members
+                    .iter()
+                    .map(|member| euclidean_distance(country, member))
+                    .sum::<f64>()
+                    / members.len() as f64,
+            )
+        }
+    };
+
+    let mut total = 0.0;
+    for country in countries {
+        let own_cluster = match country.cluster {
+            Some(cluster) => cluster,
+            None => continue,
+        };
+
+        let a = mean_distance_to(country, own_cluster);
+        let b = (0..k)
+            .filter(|&cluster| cluster != own_cluster)
+            .filter_map(|cluster| mean_distance_to(country, cluster))
+            .fold(f64::INFINITY, f64::min);
+
+        let silhouette = match a {
+            Some(a) if b.is_finite() => (b - a) / a.max(b),
+            _ => 0.0,
+        };
+
+        total += silhouette;
+    }
+
+    total / countries.len() as f64
+}
+
+// Sweeps k over `k_range`, running k-means for each value and reporting WCSS
+// (for the elbow method) and mean silhouette. Returns the k with the highest
+// mean silhouette alongside the full (k, wcss, silhouette) table.
+fn choose_k(
+    countries: &[Country],
+    k_range: std::ops::RangeInclusive<usize>,
+) -> (usize, Vec<(usize, f64, f64)>) {
+    let mut table = Vec::new();
+    let mut best_k = *k_range.start();
+    let mut best_silhouette = f64::NEG_INFINITY;
+
+    for k in k_range {
+        let clustered = kmeans(countries.to_vec(), k, 100, InitMethod::KmeansPlusPlus);
+        let centroids = update_centroids(&clustered, k);
+        let wcss_value = wcss(&clustered, &centroids);
+        let silhouette = mean_silhouette(&clustered, k);
+
+        table.push((k, wcss_value, silhouette));
+
+        if silhouette > best_silhouette {
+            best_silhouette = silhouette;
+            best_k = k;
+        }
+    }
+
+    (best_k, table)
+}
+
+// The member of a cluster that minimizes total distance to its cluster-mates,
+// i.e. the most "representative" country in that cluster.
+fn medoids(clustered_countries: &[Country], k: usize) -> Vec<Country> {
+    let mut medoids = Vec::new();
+
+    for cluster in 0..k {
+        let members: Vec<&Country> = clustered_countries
+            .iter()
+            .filter(|country| country.cluster == Some(cluster))
+            .collect();
+
+        if let Some(medoid) = members.iter().min_by(|a, b| {
+            let cost_a: f64 = members.iter().map(|m| euclidean_distance(a, m)).sum();
+            let cost_b: f64 = members.iter().map(|m| euclidean_distance(b, m)).sum();
+            cost_a.partial_cmp(&cost_b).unwrap_or(Ordering::Equal)
+        }) {
+            medoids.push((*medoid).clone());
+        }
+    }
+
+    medoids
+}
+
+fn tour_length(medoids: &[Country], tour: &[usize]) -> f64 {
+    (0..tour.len())
+        .map(|i| {
+            let j = (i + 1) % tour.len();
+            euclidean_distance(&medoids[tour[i]], &medoids[tour[j]])
+        })
+        .sum()
+}
+
+// Picks each cluster's medoid and builds a short round trip visiting all of
+// them: nearest-neighbor construction, then 2-opt segment reversals until no
+// swap shortens the tour. Gives a compact "representative itinerary" across
+// the data's cluster structure.
+fn cluster_tour(clustered_countries: &[Country]) -> (Vec<String>, f64) {
+    let k = clustered_countries
+        .iter()
+        .filter_map(|country| country.cluster)
+        .max()
+        .map_or(0, |max_cluster| max_cluster + 1);
+    let medoids = medoids(clustered_countries, k);
+
+    if medoids.len() < 2 {
+        return (medoids.into_iter().map(|country| country.name).collect(), 0.0);
+    }
+
+    let n = medoids.len();
+    let mut visited = vec![false; n];
+    let mut tour = vec![0];
+    visited[0] = true;
+
+    for _ in 1..n {
+        let last = *tour.last().unwrap();
+        let next = (0..n)
+            .filter(|&candidate| !visited[candidate])
+            .min_by(|&a, &b| {
+                euclidean_distance(&medoids[last], &medoids[a])
+                    .partial_cmp(&euclidean_distance(&medoids[last], &medoids[b]))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        visited[next] = true;
+        tour.push(next);
+    }
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(1) {
+            for j in (i + 2)..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+
+                let mut candidate = tour.clone();
+                candidate[(i + 1)..=j].reverse();
+                if tour_length(&medoids, &candidate) < tour_length(&medoids, &tour) {
+                    tour = candidate;
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    let total_length = tour_length(&medoids, &tour);
+    let names = tour.iter().map(|&i| medoids[i].name.clone()).collect();
+    (names, total_length)
+}
+
+// Counts country pairs that are grouped together in one clustering but not
+// the other. Cluster IDs are just centroid indices assigned independently by
+// each run, so comparing `a.cluster != b.cluster` directly is meaningless
+// across two separate kmeans calls (the labels can be permuted even when the
+// groupings agree); comparing co-assignment per pair is label-invariant.
+fn pairwise_cluster_disagreement(a: &[Country], b: &[Country]) -> usize {
+    let mut disagreements = 0;
+    for i in 0..a.len() {
+        for j in (i + 1)..a.len() {
+            let same_in_a = a[i].cluster == a[j].cluster;
+            let same_in_b = b[i].cluster == b[j].cluster;
+            if same_in_a != same_in_b {
+                disagreements += 1;
+            }
+        }
+    }
+    disagreements
+}
+
+fn build_graph(countries: &[Country], threshold: f64) -> Vec<(String, Vec<(String, f64)>)> {
     let mut adjacency_list = Vec::new();
 
     for country in countries {
@@ -119,7 +376,7 @@ fn build_graph(countries: &[Country], threshold: f64) -> Vec<(String, Vec<String
             if country.name != other.name {
                 let distance = euclidean_distance(country, other);
                 if distance < threshold {
-                    neighbors.push(other.name.clone());
+                    neighbors.push((other.name.clone(), distance));
                 }
             }
         }
@@ -129,29 +386,229 @@ fn build_graph(countries: &[Country], threshold: f64) -> Vec<(String, Vec<String
     adjacency_list
 }
 
+// Flood-fills the threshold adjacency list to find the groups of countries
+// reachable from each other at the `threshold` distance, making that
+// parameter's effect visible instead of arbitrary.
+fn connected_components(graph: &[(String, Vec<(String, f64)>)]) -> Vec<Vec<String>> {
+    let index_of: HashMap<&str, usize> = graph
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (name.as_str(), i))
+        .collect();
+
+    let mut visited = vec![false; graph.len()];
+    let mut components = Vec::new();
+
+    for start in 0..graph.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        visited[start] = true;
+
+        while let Some(node) = queue.pop_front() {
+            component.push(graph[node].0.clone());
+            for (neighbor_name, _) in &graph[node].1 {
+                let neighbor = index_of[neighbor_name.as_str()];
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+// For each threshold-connected component, reports how its members are split
+// across the k-means clusters, so a user can see whether the distance
+// neighborhoods agree with the learned clusters.
+fn component_cluster_breakdown(
+    components: &[Vec<String>],
+    clustered_countries: &[Country],
+) -> Vec<HashMap<usize, Vec<String>>> {
+    let cluster_of: HashMap<&str, usize> = clustered_countries
+        .iter()
+        .filter_map(|country| country.cluster.map(|cluster| (country.name.as_str(), cluster)))
+        .collect();
+
+    components
+        .iter()
+        .map(|component| {
+            let mut breakdown: HashMap<usize, Vec<String>> = HashMap::new();
+            for name in component {
+                if let Some(&cluster) = cluster_of.get(name.as_str()) {
+                    breakdown.entry(cluster).or_default().push(name.clone());
+                }
+            }
+            breakdown
+        })
+        .collect()
+}
+
+// Wraps an f64 so it can sit in a BinaryHeap, which requires Ord; f64 only
+// implements PartialOrd because of NaN, and distances here are never NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NonNan(f64);
+
+impl Eq for NonNan {}
+
+impl PartialOrd for NonNan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonNan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+fn shortest_path(
+    graph: &[(String, Vec<(String, f64)>)],
+    from: &str,
+    to: &str,
+) -> Option<(Vec<String>, f64)> {
+    let index_of: HashMap<&str, usize> = graph
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (name.as_str(), i))
+        .collect();
+
+    let start = *index_of.get(from)?;
+    let target = *index_of.get(to)?;
+
+    let mut dist = vec![f64::INFINITY; graph.len()];
+    let mut prev: Vec<Option<usize>> = vec![None; graph.len()];
+    let mut heap = BinaryHeap::new();
+
+    dist[start] = 0.0;
+    heap.push(Reverse((NonNan(0.0), start)));
+
+    while let Some(Reverse((NonNan(current_dist), node))) = heap.pop() {
+        if node == target {
+            break;
+        }
+        if current_dist > dist[node] {
+            continue;
+        }
+
+        for (neighbor_name, weight) in &graph[node].1 {
+            let neighbor = index_of[neighbor_name.as_str()];
+            let next_dist = current_dist + weight;
+            if next_dist < dist[neighbor] {
+                dist[neighbor] = next_dist;
+                prev[neighbor] = Some(node);
+                heap.push(Reverse((NonNan(next_dist), neighbor)));
+            }
+        }
+    }
+
+    if dist[target].is_infinite() {
+        return None;
+    }
+
+    let mut path = vec![target];
+    while let Some(prev_node) = prev[*path.last().unwrap()] {
+        path.push(prev_node);
+    }
+    path.reverse();
+
+    let names = path.into_iter().map(|i| graph[i].0.clone()).collect();
+    Some((names, dist[target]))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let file_path = "life expectancy.csv";
-    let countries = load_and_clean_data(file_path)?;
+    let mut countries = load_and_clean_data(file_path)?;
 
     if countries.is_empty() {
         println!("No valid data found.");
         return Ok(());
     }
 
-    // Step 1: Perform K-Means Clustering
-    let k = 5;
+    // Flip to compare raw vs. standardized distances/clusters.
+    let use_standardized_features = true;
+    let raw_countries = countries.clone();
+    let scaling_params = if use_standardized_features {
+        Some(standardize(&mut countries))
+    } else {
+        None
+    };
+
+    if let Some(params) = &scaling_params {
+        println!("Standardized features to z-scores: {:?}", params);
+    }
+
+    // Step 1: Sweep k to find a reasonable cluster count via silhouette/WCSS.
+    let (k, k_table) = choose_k(&countries, 2..=8);
+    println!("k sweep (k, wcss, mean silhouette):");
+    for (candidate_k, candidate_wcss, candidate_silhouette) in &k_table {
+        println!(
+            "  k={} wcss={:.4} silhouette={:.4}",
+            candidate_k, candidate_wcss, candidate_silhouette
+        );
+    }
+    println!("Chosen k = {}", k);
+
+    // Step 2: Perform K-Means Clustering
     let max_iterations = 100;
-    let clustered_countries = kmeans(countries.clone(), k, max_iterations);
+    let clustered_countries = kmeans(
+        countries.clone(),
+        k,
+        max_iterations,
+        InitMethod::KmeansPlusPlus,
+    );
+
+    if use_standardized_features {
+        let raw_clustered = kmeans(raw_countries, k, max_iterations, InitMethod::KmeansPlusPlus);
+        let disagreeing_pairs = pairwise_cluster_disagreement(&clustered_countries, &raw_clustered);
+        println!(
+            "\n{} country pairs disagree on being clustered together between standardized and raw distances.",
+            disagreeing_pairs
+        );
+    }
 
-    // Step 2: Build Graph Based on Threshold
-    let threshold = 0.5;
+    // Step 3: Build Graph Based on Threshold.
+    // Euclidean distances are in z-score units once features are standardized,
+    // so the "similar countries" cutoff needs to be on that scale too.
+    let threshold = if use_standardized_features { 1.0 } else { 0.5 };
     let graph = build_graph(&clustered_countries, threshold);
 
     println!("Graph connections:");
-    for (country, neighbors) in graph {
+    for (country, neighbors) in &graph {
         println!("{} -> {:?}", country, neighbors);
     }
 
+    if let (Some((first, _)), Some((last, _))) = (graph.first(), graph.last()) {
+        match shortest_path(&graph, first, last) {
+            Some((path, distance)) => {
+                println!("\nShortest path {} -> {}: {:?} (distance {:.4})", first, last, path, distance);
+            }
+            None => println!("\nNo path found between {} and {}", first, last),
+        }
+    }
+
+    // Step 4: Check threshold-connected components against the k-means clusters.
+    let components = connected_components(&graph);
+    let breakdown = component_cluster_breakdown(&components, &clustered_countries);
+
+    println!("\nConnected components at threshold {}:", threshold);
+    for (component, clusters) in components.iter().zip(&breakdown) {
+        println!("{:?} -> clusters {:?}", component, clusters);
+    }
+
+    // Step 5: Build a representative tour across the cluster medoids.
+    let (tour, tour_distance) = cluster_tour(&clustered_countries);
+    println!("\nCluster tour: {:?} (length {:.4})", tour, tour_distance);
+
     println!("\nClustered Results:");
     for country in clustered_countries {
         println!("{} - Cluster: {}", country.name, country.cluster.unwrap_or_default());
@@ -221,11 +678,42 @@ mod tests {
             },
         ];
 
-        let clustered_countries = kmeans(countries, 2, 10);
+        let clustered_countries = kmeans(countries, 2, 10, InitMethod::Random);
         assert!(clustered_countries.len() > 0);
         assert!(clustered_countries.iter().all(|c| c.cluster.is_some()));
     }
 
+    #[test]
+    fn test_kmeans_plus_plus_centroids() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 15.0,
+                non_communicable: 25.0,
+                co2: 10.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryC".to_string(),
+                communicable: 30.0,
+                non_communicable: 35.0,
+                co2: 20.0,
+                cluster: None,
+            },
+        ];
+
+        let centroids = kmeans_plus_plus_centroids(&countries, 2);
+        assert_eq!(centroids.len(), 2);
+        assert_ne!(centroids[0], centroids[1]);
+    }
+
     #[test]
     fn test_build_graph() {
         let countries = vec![
@@ -248,4 +736,219 @@ mod tests {
         let graph = build_graph(&countries, 10.0);
         assert_eq!(graph.len(), 2);
     }
+
+    #[test]
+    fn test_shortest_path() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 15.0,
+                non_communicable: 25.0,
+                co2: 10.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryC".to_string(),
+                communicable: 30.0,
+                non_communicable: 35.0,
+                co2: 20.0,
+                cluster: None,
+            },
+        ];
+
+        let graph = build_graph(&countries, 100.0);
+        let (path, distance) = shortest_path(&graph, "CountryA", "CountryC").unwrap();
+        assert_eq!(path.first(), Some(&"CountryA".to_string()));
+        assert_eq!(path.last(), Some(&"CountryC".to_string()));
+        assert!(distance > 0.0);
+    }
+
+    #[test]
+    fn test_shortest_path_disconnected() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 1000.0,
+                non_communicable: 1000.0,
+                co2: 1000.0,
+                cluster: None,
+            },
+        ];
+
+        let graph = build_graph(&countries, 0.5);
+        assert!(shortest_path(&graph, "CountryA", "CountryB").is_none());
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 11.0,
+                non_communicable: 21.0,
+                co2: 6.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryC".to_string(),
+                communicable: 100.0,
+                non_communicable: 110.0,
+                co2: 90.0,
+                cluster: None,
+            },
+        ];
+
+        let graph = build_graph(&countries, 5.0);
+        let components = connected_components(&graph);
+        assert_eq!(components.len(), 2);
+    }
+
+    #[test]
+    fn test_component_cluster_breakdown() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: Some(0),
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 11.0,
+                non_communicable: 21.0,
+                co2: 6.0,
+                cluster: Some(1),
+            },
+        ];
+
+        let graph = build_graph(&countries, 5.0);
+        let components = connected_components(&graph);
+        let breakdown = component_cluster_breakdown(&components, &countries);
+
+        assert_eq!(breakdown.len(), components.len());
+        let total_members: usize = breakdown.iter().map(|b| b.values().map(Vec::len).sum::<usize>()).sum();
+        assert_eq!(total_members, countries.len());
+    }
+
+    #[test]
+    fn test_cluster_tour() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: Some(0),
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 15.0,
+                non_communicable: 25.0,
+                co2: 10.0,
+                cluster: Some(1),
+            },
+            Country {
+                name: "CountryC".to_string(),
+                communicable: 100.0,
+                non_communicable: 110.0,
+                co2: 90.0,
+                cluster: Some(2),
+            },
+        ];
+
+        let (tour, distance) = cluster_tour(&countries);
+        assert_eq!(tour.len(), 3);
+        assert!(distance > 0.0);
+    }
+
+    #[test]
+    fn test_standardize() {
+        let mut countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 200.0,
+                co2: 1.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 20.0,
+                non_communicable: 400.0,
+                co2: 1.0,
+                cluster: None,
+            },
+        ];
+
+        let params = standardize(&mut countries);
+        assert!((countries[0].communicable + countries[1].communicable).abs() < 1e-9);
+        assert!(params.communicable_std > 0.0);
+        // co2 is constant across both countries, so it's left unscaled.
+        assert_eq!(countries[0].co2, 1.0);
+        assert_eq!(params.co2_std, 0.0);
+    }
+
+    #[test]
+    fn test_choose_k() {
+        let countries = vec![
+            Country {
+                name: "CountryA".to_string(),
+                communicable: 10.0,
+                non_communicable: 20.0,
+                co2: 5.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryB".to_string(),
+                communicable: 11.0,
+                non_communicable: 21.0,
+                co2: 6.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryC".to_string(),
+                communicable: 100.0,
+                non_communicable: 110.0,
+                co2: 90.0,
+                cluster: None,
+            },
+            Country {
+                name: "CountryD".to_string(),
+                communicable: 101.0,
+                non_communicable: 111.0,
+                co2: 91.0,
+                cluster: None,
+            },
+        ];
+
+        let (best_k, table) = choose_k(&countries, 2..=3);
+        assert_eq!(table.len(), 2);
+        assert_eq!(best_k, 2);
+        for (_, wcss_value, silhouette) in &table {
+            assert!(*wcss_value >= 0.0);
+            assert!(*silhouette >= -1.0 && *silhouette <= 1.0);
+        }
+    }
 }
\ No newline at end of file